@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Errors returned by fallible operations in this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// The operation needs a direction, but the vector has a magnitude of zero.
+    ZeroMagnitude,
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathError::ZeroMagnitude => write!(f, "vector has zero magnitude"),
+        }
+    }
+}
+
+impl Error for MathError {}
@@ -3,7 +3,9 @@ extern crate num_traits;
 #[macro_use]
 pub mod macros;
 
+pub mod error;
 pub mod matrix;
 pub mod trigonometry;
 pub mod vector_3d;
-pub mod vector;
\ No newline at end of file
+pub mod vector;
+pub mod vector_like;
\ No newline at end of file
@@ -1,3 +1,5 @@
+use crate::error::MathError;
+use itertools::izip;
 use num_traits::Float;
 
 mod iterator;
@@ -17,6 +19,14 @@ impl<T: Float, const N: usize> Vector<T, N> {
         }
     }
 
+    /// Builds a vector by calling `f` with each index `0..N`.
+    pub fn from_fn<F: Fn(usize) -> T>(f: F) -> Vector<T, N> {
+        Vector {
+            components: std::array::from_fn(f),
+            dimensions: N,
+        }
+    }
+
     pub fn zero() -> Vector<T, N> {
         Vector {
             components: [T::from(0.0).expect("REASON"); N],
@@ -48,6 +58,17 @@ impl<T: Float, const N: usize> Vector<T, N> {
         result
     }
 
+    /// Weighted dot product `Σ wᵢ·aᵢ·bᵢ`; weights of one reproduce `dot`.
+    pub fn dot_weighted(&self, rhs: &Self, weights: &Self) -> T {
+        let mut result: T = T::zero();
+
+        for (ele1, ele2, weight) in izip!(&self.components, &rhs.components, &weights.components) {
+            result = result + *weight * *ele1 * *ele2;
+        }
+
+        result
+    }
+
     pub fn unit_vector(&self) -> Vector<T, N> {
         let mut normalized_components: [T; N] = self.components;
         let magnitude: T = T::from(self.magnitude()).expect("magnitude could not be calculated");
@@ -62,10 +83,148 @@ impl<T: Float, const N: usize> Vector<T, N> {
         }
     }
 
+    /// Normalizes the vector in place, leaving it untouched and returning
+    /// `MathError::ZeroMagnitude` if it has no direction.
+    pub fn normalize_inplace(&mut self) -> Result<(), MathError> {
+        let magnitude = self.magnitude();
+        if magnitude == T::zero() {
+            return Err(MathError::ZeroMagnitude);
+        }
+
+        for x in self.iter_mut() {
+            *x = *x / magnitude;
+        }
+
+        Ok(())
+    }
+
     pub fn angle_rad(&self, rhs: &Self) -> T {
         T::acos(self.dot(rhs) / self.magnitude() * rhs.magnitude())
     }
 
+    /// Divides each component by the matching component of `other`.
+    ///
+    /// Follows IEEE float division: a zero in `other` yields `±inf` for a non-zero numerator
+    /// and `NaN` for a zero numerator.
+    pub fn hadamard_div(&self, other: &Self) -> Vector<T, N> {
+        let mut quotient = self.clone();
+
+        for (e1, e2) in quotient.iter_mut().zip(other.iter()) {
+            *e1 = *e1 / *e2;
+        }
+
+        quotient
+    }
+
+    /// Arithmetic mean of the components. `NaN` for a zero-length vector.
+    pub fn mean(&self) -> T {
+        let mut sum: T = T::zero();
+
+        for x in self.components {
+            sum = sum + x;
+        }
+
+        sum / T::from(N).unwrap()
+    }
+
+    /// Population variance of the components (divides by `N`, not `N - 1`).
+    pub fn variance(&self) -> T {
+        let mean = self.mean();
+        let mut sum_of_squares: T = T::zero();
+
+        for x in self.components {
+            let deviation = x - mean;
+            sum_of_squares = sum_of_squares + deviation * deviation;
+        }
+
+        sum_of_squares / T::from(N).unwrap()
+    }
+
+    /// Z-score transform: shifts the components to a mean of zero and scales them to a
+    /// variance of one. Returns `None` when all components are equal (zero standard deviation).
+    pub fn standardize(&self) -> Option<Vector<T, N>> {
+        let mean = self.mean();
+        let std_dev = self.variance().sqrt();
+        if std_dev == T::zero() || std_dev.is_nan() {
+            return None;
+        }
+
+        Some(Vector {
+            components: self.components.map(|x| (x - mean) / std_dev),
+            dimensions: N,
+        })
+    }
+
+    /// Pads the vector with `fill` up to the next power-of-two length (e.g. for FFT input).
+    ///
+    /// Stable Rust cannot compute `N.next_power_of_two()` in a return type, so the output
+    /// length is a second const parameter `M` that the caller names or lets inference pick up
+    /// from the binding. Using an `M` other than `N.next_power_of_two()` fails to compile.
+    /// A length that is already a power of two is returned unchanged.
+    pub fn pad_pow2<const M: usize>(self, fill: T) -> Vector<T, M> {
+        const { assert!(M == N.next_power_of_two(), "M must equal N.next_power_of_two()") };
+
+        let mut components = [fill; M];
+        components[..N].copy_from_slice(&self.components);
+
+        Vector {
+            components,
+            dimensions: M,
+        }
+    }
+
+    /// Returns `true` if every component of `other` is within `epsilon` of the matching
+    /// component of `self`.
+    pub fn equals_approx(&self, other: &Self, epsilon: T) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(e1, e2)| (*e1 - *e2).abs() <= epsilon)
+    }
+
+    /// Indices of the `k` components with the largest absolute values, largest first.
+    ///
+    /// Ties keep the lower index first, and a `k` larger than `N` returns all `N` indices.
+    /// Magnitudes are compared with the IEEE total order, so `NaN` components rank highest.
+    pub fn top_k_indices(&self, k: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..N).collect();
+        indices.sort_by(|a, b| {
+            let a = self.components[*a].abs().to_f64().unwrap();
+            let b = self.components[*b].abs().to_f64().unwrap();
+            b.total_cmp(&a)
+        });
+        indices.truncate(k);
+
+        indices
+    }
+
+    /// Zeroes every component except the `k` largest in absolute value (the hard-thresholding
+    /// step of sparse approximation). Ties are resolved like `top_k_indices`.
+    pub fn keep_top_k(&self, k: usize) -> Vector<T, N> {
+        let mut sparse: Vector<T, N> = Vector::zero();
+
+        for index in self.top_k_indices(k) {
+            sparse.components[index] = self.components[index];
+        }
+
+        sparse
+    }
+
+    /// Appends one component, e.g. the `1` that homogenizes a point.
+    ///
+    /// As with `pad_pow2`, stable Rust cannot spell `N + 1` in the return type, so the output
+    /// length is a const parameter `M` that must equal `N + 1`; anything else fails to compile.
+    pub fn with_appended<const M: usize>(self, value: T) -> Vector<T, M> {
+        const { assert!(M == N + 1, "M must equal N + 1") };
+
+        let mut components = [value; M];
+        components[..N].copy_from_slice(&self.components);
+
+        Vector {
+            components,
+            dimensions: M,
+        }
+    }
+
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
         self.components.iter_mut()
     }
@@ -73,4 +232,9 @@ impl<T: Float, const N: usize> Vector<T, N> {
     pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.components.iter()
     }
+
+    /// Iterates over `(index, &component)` pairs.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.components.iter().enumerate()
+    }
 }
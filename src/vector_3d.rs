@@ -1,11 +1,16 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
 use crate::vector_3d::iterator::{Iter, IterMut};
 use num_traits::Float;
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
+pub mod approx;
 pub mod iterator;
 pub mod ops;
+pub mod spline;
+pub mod stats;
 
 /// A generic 3-dimensional vector_3d struct with components specified in the i, j, and k directions.
 /// This struct represents a mathematical vector_3d in a 3D space, where each component's type must
@@ -92,11 +97,92 @@ pub fn k_hat<T: Float>() -> Vector3d<T> {
     }
 }
 
+/// Splits an acceleration into its tangential and normal parts relative to a velocity.
+///
+/// # Parameters
+/// - `velocity`: The velocity defining the direction of travel.
+/// - `accel`: The acceleration to decompose.
+///
+/// # Returns
+/// A tuple `(tangential, normal)` where `tangential` is the projection of `accel` onto
+/// `velocity` (the part that changes speed) and `normal` is the remainder perpendicular to it
+/// (the part that changes direction). `tangential + normal == accel`.
+///
+/// If `velocity` is the zero vector_3d there is no direction of travel, so the result is
+/// `(zero(), accel)`.
+pub fn split_tangential_normal<T: Float>(
+    velocity: &Vector3d<T>,
+    accel: &Vector3d<T>,
+) -> (Vector3d<T>, Vector3d<T>) {
+    if velocity.magnitude() == 0.0 {
+        return (zero(), accel.clone());
+    }
+
+    let tangential = Vector3d::project(accel, velocity);
+    let normal = accel.clone() - tangential.clone();
+    (tangential, normal)
+}
+
+/// Calculates the inertia tensor of a set of point masses about the point `about`.
+///
+/// For offsets `r = p - about` the tensor is `Σ m (|r|² I - r ⊗ r)`. It is returned as a
+/// row-major 3×3 array where `[a][b]` pairs components `a` and `b` (0 = i, 1 = j, 2 = k),
+/// the same layout as `RunningStats::covariance`, until a matrix type is available.
+///
+/// # Panics
+/// Panics if `points` and `masses` have different lengths. Unlike `center_of_mass`, which
+/// returns `None` because a zero total mass leaves it undefined, every input with matching
+/// lengths has a valid tensor (zero mass gives the zero tensor). A length mismatch can only be
+/// a bug at the call site, so it is asserted rather than folded into an `Option`.
+pub fn inertia_tensor<T: Float>(
+    points: &[Vector3d<T>],
+    masses: &[f32],
+    about: Vector3d<T>,
+) -> [[T; 3]; 3] {
+    assert_eq!(points.len(), masses.len(), "every point needs a mass");
+
+    let mut tensor = [[T::zero(); 3]; 3];
+    for (point, mass) in points.iter().zip(masses.iter()) {
+        let mass = T::from(*mass).unwrap();
+        let r = point.clone() - about.clone();
+        let r_squared = r.i * r.i + r.j * r.j + r.k * r.k;
+
+        for (row, cells) in tensor.iter_mut().enumerate() {
+            for (column, cell) in cells.iter_mut().enumerate() {
+                let diagonal = if row == column { r_squared } else { T::zero() };
+                *cell = *cell + mass * (diagonal - r[row as u8] * r[column as u8]);
+            }
+        }
+    }
+
+    tensor
+}
+
 impl<T: Float + Copy + Clone> Vector3d<T> {
     pub fn new(i: T, j: T, k: T) -> Self {
         Vector3d { i, j, k }
     }
 
+    /// Builds a vector_3d from a runtime-sized slice, succeeding only when it holds exactly
+    /// three elements (taken as i, j, k).
+    pub fn try_from_slice(s: &[T]) -> Option<Self> {
+        match s {
+            [i, j, k] => Some(Vector3d::new(*i, *j, *k)),
+            _ => None,
+        }
+    }
+
+    /// Builds a vector_3d from 8-bit RGB channels, mapping each byte into `[0, 1]` by dividing
+    /// by 255. This is the inverse of `to_color_bytes`.
+    pub fn from_color_bytes(rgb: [u8; 3]) -> Self {
+        let to_component = |byte: u8| T::from(byte).unwrap() / T::from(255).unwrap();
+        Vector3d {
+            i: to_component(rgb[0]),
+            j: to_component(rgb[1]),
+            k: to_component(rgb[2]),
+        }
+    }
+
     /// Returns the magnitude (length) of the vector_3d calculated as sqrt(i² + j² + k²)
     pub fn magnitude(&self) -> f32 {
         (self.i * self.i + self.j * self.j + self.k * self.k)
@@ -204,6 +290,66 @@ impl<T: Float + Copy + Clone> Vector3d<T> {
         f32::acos(self.dot(other) / (self.magnitude() * other.magnitude()))
     }
 
+    /// Calculates the cosine of the angle between two vectors without taking the `acos`.
+    ///
+    /// The result is `dot / (|self| * |other|)` clamped into `[-1, 1]`, so rounding error on
+    /// (anti)parallel inputs can never push it out of the domain of `acos`. Each vector_3d is
+    /// first scaled so its largest component is `±1`, which keeps the squared terms from
+    /// overflowing for very large inputs or underflowing to zero for very small ones.
+    ///
+    /// # Parameters
+    /// - `&self`: A reference to the current vector_3d.
+    /// - `other`: A reference to another vector_3d to compare against.
+    ///
+    /// # Returns
+    /// - `Some(f32)`: The cosine of the angle between the two vectors.
+    /// - `None`: If either vector_3d has zero magnitude or a non-finite component.
+    ///
+    /// # Example
+    /// ```
+    /// use math_lib::vector_3d::Vector3d;
+    ///
+    /// let vector1 = Vector3d::new(1.0, 0.0, 0.0);
+    /// let vector2 = Vector3d::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!(vector1.cos_angle(&vector2), Some(0.0));
+    /// ```
+    pub fn cos_angle(&self, other: &Self) -> Option<f32> {
+        let rescale = |v: &Self| -> Self {
+            let largest = v.i.abs().max(v.j.abs()).max(v.k.abs());
+            Vector3d {
+                i: v.i / largest,
+                j: v.j / largest,
+                k: v.k / largest,
+            }
+        };
+        let (a, b) = (rescale(self), rescale(other));
+
+        // a zero vector_3d rescales to NaN, as does an infinite component
+        let cos = a.dot(&b) / (a.magnitude() * b.magnitude());
+        if !cos.is_finite() {
+            return None;
+        }
+
+        Some(cos.clamp(-1.0, 1.0))
+    }
+
+    /// Returns `true` if the two vectors are parallel (or anti-parallel), i.e. the magnitude of
+    /// their cross product is within `epsilon` of zero.
+    ///
+    /// `epsilon` is absolute and is compared against a value proportional to
+    /// `|self|·|other|`, so the same `epsilon` is stricter for long vectors and looser for short
+    /// ones. Scale it by the magnitudes (or pass unit vectors) for an angle-based test.
+    pub fn is_parallel(&self, other: &Self, epsilon: f32) -> bool {
+        self.cross(other).magnitude() <= epsilon
+    }
+
+    /// Returns `true` if the two vectors are perpendicular, i.e. their dot product is within
+    /// `epsilon` of zero. `epsilon` is absolute, as in `is_parallel`.
+    pub fn is_perpendicular(&self, other: &Self, epsilon: f32) -> bool {
+        self.dot(other).abs() <= epsilon
+    }
+
     /// Calculates and returns the unit vector_3d (a vector_3d with a magnitude of 1) in the same direction
     /// as the current vector_3d.
     ///
@@ -266,6 +412,506 @@ impl<T: Float + Copy + Clone> Vector3d<T> {
         }
     }
 
+    /// Projects `u` onto `axis` like `project`, but limits how far along the axis the result
+    /// may go.
+    ///
+    /// The projection is written as `axis * t`; `t` is clamped into `[min_t, max_t]` before the
+    /// vector_3d is rebuilt, which models a slider joint with a limited range of travel.
+    /// `t` is measured in multiples of `axis`, so with a unit axis the limits are distances.
+    ///
+    /// # Panics
+    /// Panics if `min_t > max_t` or either limit is `NaN`.
+    pub fn project_clamped(u: &Self, axis: &Self, min_t: f32, max_t: f32) -> Self {
+        let t = (u.dot(axis) / axis.dot(axis)).clamp(min_t, max_t);
+        axis.clone() * T::from(t).unwrap()
+    }
+
+    /// Removes the component of the vector_3d that points into (or out of) a surface, leaving
+    /// only the part that slides along it.
+    ///
+    /// Unlike a reflection there is no bounce: the velocity into the surface is simply dropped.
+    ///
+    /// # Parameters
+    /// - `normal`: The surface normal. It is assumed to be unit length; a non-unit normal
+    ///   removes the wrong amount and the result will not lie in the surface plane.
+    ///
+    /// # Returns
+    /// `self - normal * (self · normal)`, a vector_3d perpendicular to `normal`.
+    pub fn slide_along_surface(&self, normal: &Self) -> Self {
+        let into_surface = T::from(self.dot(normal)).unwrap();
+        self.clone() - normal.clone() * into_surface
+    }
+
+    /// Returns `true` if `pred` holds for every component (i, j and k).
+    pub fn all<F: Fn(T) -> bool>(&self, pred: F) -> bool {
+        pred(self.i) && pred(self.j) && pred(self.k)
+    }
+
+    /// Returns `true` if `pred` holds for at least one component (i, j or k).
+    pub fn any<F: Fn(T) -> bool>(&self, pred: F) -> bool {
+        pred(self.i) || pred(self.j) || pred(self.k)
+    }
+
+    /// Clamps each component into `[-1, 1]`, e.g. to keep a point inside normalized device
+    /// coordinates. `NaN` components are kept as `NaN` rather than clamped.
+    pub fn clamp_to_unit_cube(&self) -> Self {
+        let one = T::one();
+        let clamp = |x: T| -> T {
+            if x.is_nan() {
+                x
+            } else {
+                x.max(-one).min(one)
+            }
+        };
+
+        Vector3d {
+            i: clamp(self.i),
+            j: clamp(self.j),
+            k: clamp(self.k),
+        }
+    }
+
+    /// Returns the point on the surface of a sphere that is closest to `self`.
+    ///
+    /// The point is found by moving from `center` along the direction towards `self` until the
+    /// distance equals `radius`, so points inside and outside the sphere are both mapped onto
+    /// its surface.
+    ///
+    /// # Parameters
+    /// - `center`: The center of the sphere.
+    /// - `radius`: The radius of the sphere.
+    ///
+    /// # Returns
+    /// The projected point. If `self` is exactly `center` every surface point is equally close,
+    /// and `center + i_hat() * radius` is returned.
+    pub fn project_to_sphere(&self, center: &Self, radius: f32) -> Self {
+        let offset = self.clone() - center.clone();
+        let radius = T::from(radius).unwrap();
+
+        if offset.magnitude() == 0.0 {
+            return center.clone() + i_hat() * radius;
+        }
+
+        center.clone() + offset.unit_vector() * radius
+    }
+
+    /// Returns the Manhattan (L1) distance to `other`: the sum of the absolute component
+    /// differences.
+    pub fn manhattan_distance(&self, other: &Self) -> f32 {
+        ((self.i - other.i).abs() + (self.j - other.j).abs() + (self.k - other.k).abs())
+            .to_f32()
+            .unwrap()
+    }
+
+    /// Returns the Chebyshev (L∞) distance to `other`: the largest absolute component
+    /// difference.
+    pub fn chebyshev_distance(&self, other: &Self) -> f32 {
+        (self.i - other.i)
+            .abs()
+            .max((self.j - other.j).abs())
+            .max((self.k - other.k).abs())
+            .to_f32()
+            .unwrap()
+    }
+
+    /// Returns `true` if every component of `other` is within `epsilon` of the matching
+    /// component of `self`.
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.chebyshev_distance(other) <= epsilon
+    }
+
+    /// Treats the vector_3d as an RGB color and converts it to 8-bit channels.
+    ///
+    /// Each component is clamped into `[0, 1]`, scaled by 255 and rounded to the nearest
+    /// integer, with halves rounded away from zero (so `0.5` becomes `128`). `NaN` components
+    /// map to `0`.
+    pub fn to_color_bytes(&self) -> [u8; 3] {
+        let to_byte = |x: T| -> u8 {
+            let x = x.to_f32().unwrap();
+            if x.is_nan() {
+                return 0;
+            }
+            (x.clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+
+        [to_byte(self.i), to_byte(self.j), to_byte(self.k)]
+    }
+
+    /// Calculates the barycentric coordinates of `p` with respect to the triangle `abc`.
+    ///
+    /// Each weight is the ratio of the signed area of the sub-triangle opposite a vertex to the
+    /// area of `abc`, measured with cross products along the triangle normal. `p` is assumed to
+    /// lie in the plane of the triangle; otherwise the weights describe its projection onto it.
+    ///
+    /// # Returns
+    /// - `Some((u, v, w))`: Weights for `a`, `b` and `c` such that `u + v + w == 1` and
+    ///   `p == a * u + b * v + c * w`. All three lie in `[0, 1]` when `p` is inside the triangle.
+    /// - `None`: If the triangle is degenerate (its vertices are collinear).
+    pub fn barycentric_coords(p: &Self, a: &Self, b: &Self, c: &Self) -> Option<(f32, f32, f32)> {
+        let normal = (b.clone() - a.clone()).cross(&(c.clone() - a.clone()));
+        let area = normal.dot(&normal);
+        if area == 0.0 {
+            return None;
+        }
+
+        let to_a = a.clone() - p.clone();
+        let to_b = b.clone() - p.clone();
+        let to_c = c.clone() - p.clone();
+
+        let u = to_b.cross(&to_c).dot(&normal) / area;
+        let v = to_c.cross(&to_a).dot(&normal) / area;
+        Some((u, v, 1.0 - u - v))
+    }
+
+    /// Reflects the vector_3d off a surface with the given normal.
+    ///
+    /// # Parameters
+    /// - `normal`: The surface normal, assumed to be unit length.
+    ///
+    /// # Returns
+    /// `self - normal * 2(self · normal)`: the component along `normal` is flipped while the
+    /// component along the surface is kept.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        let scalar = T::from(2.0 * self.dot(normal)).unwrap();
+        self.clone() - normal.clone() * scalar
+    }
+
+    /// Reflects the vector_3d off a two-sided surface, regardless of which face `normal`
+    /// points out of.
+    ///
+    /// The reflection `self - normal * 2(self · normal)` is unchanged when `normal` is negated,
+    /// so no normal flipping is needed: hitting either face gives the correct bounce. This is
+    /// the same as `reflect` and exists so call sites dealing with two-sided geometry can say so.
+    pub fn reflect_two_sided(&self, normal: &Self) -> Self {
+        self.reflect(normal)
+    }
+
+    /// Refracts the vector_3d through a surface following Snell's law.
+    ///
+    /// # Parameters
+    /// - `normal`: The surface normal, assumed to be unit length and facing against `self`.
+    /// - `eta`: The ratio of refractive indices, `n_incident / n_transmitted`.
+    ///
+    /// `self` is the incident direction pointing toward the surface and is assumed to be unit
+    /// length.
+    ///
+    /// # Returns
+    /// - `Some(Self)`: The unit-length transmitted direction.
+    /// - `None`: On total internal reflection, when no transmitted ray exists.
+    pub fn refract(&self, normal: &Self, eta: f32) -> Option<Self> {
+        let cos_incident = -self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+
+        let normal_scalar = T::from(eta * cos_incident - k.sqrt()).unwrap();
+        Some(self.clone() * T::from(eta).unwrap() + normal.clone() * normal_scalar)
+    }
+
+    /// Compares two vectors lexicographically by i, then j, then k, using the IEEE total order
+    /// on each component.
+    ///
+    /// Unlike the derived `PartialEq`, this is a total order (`-0.0 < 0.0` and `NaN`s are
+    /// ordered), so it can be passed to `sort_by` for deterministic ordering.
+    pub fn total_cmp_components(&self, other: &Self) -> Ordering {
+        let cmp = |a: T, b: T| a.to_f64().unwrap().total_cmp(&b.to_f64().unwrap());
+
+        cmp(self.i, other.i)
+            .then_with(|| cmp(self.j, other.j))
+            .then_with(|| cmp(self.k, other.k))
+    }
+
+    /// Calculates the rotation that turns the direction of `self` onto the direction of `other`.
+    ///
+    /// # Returns
+    /// A tuple `(axis, angle)` where `axis` is the unit rotation axis and `angle` is in radians
+    /// within `[0, π]`, following the right-hand rule about `axis`.
+    ///
+    /// When the vectors are parallel the angle is `0` and the axis is arbitrary; when they are
+    /// anti-parallel the angle is `π` and the axis is some unit vector_3d perpendicular to
+    /// `self`. Both inputs are assumed to be non-zero.
+    pub fn rotation_to(&self, other: &Self) -> (Self, f32) {
+        let cross = self.cross(other);
+        let sin = cross.magnitude();
+        let cos = self.dot(other);
+
+        if sin <= f32::EPSILON * self.magnitude() * other.magnitude() {
+            let axis = self.any_perpendicular().unit_vector();
+            let angle = if cos < 0.0 { std::f32::consts::PI } else { 0.0 };
+            return (axis, angle);
+        }
+
+        (cross.unit_vector(), sin.atan2(cos))
+    }
+
+    /// Applies periodic boundary conditions, wrapping each component into
+    /// `[0, box_size component)`.
+    ///
+    /// Components below zero wrap around from the top of the box, so `-1` in a box of `10`
+    /// becomes `9`. Box sizes are assumed to be positive.
+    pub fn wrap(&self, box_size: &Self) -> Self {
+        let wrap = |x: T, size: T| -> T {
+            let wrapped = x - size * (x / size).floor();
+            // a tiny negative x can round up to exactly `size`
+            if wrapped >= size {
+                wrapped - size
+            } else {
+                wrapped
+            }
+        };
+
+        Vector3d {
+            i: wrap(self.i, box_size.i),
+            j: wrap(self.j, box_size.j),
+            k: wrap(self.k, box_size.k),
+        }
+    }
+
+    /// Returns the shortest displacement from `self` to `other` in a periodic box.
+    ///
+    /// The naive displacement `other - self` is wrapped component-wise into
+    /// `(-box_size / 2, box_size / 2]`, so the result always points to the closest periodic
+    /// image of `other`. Box sizes are assumed to be positive.
+    pub fn minimum_image(&self, other: &Self, box_size: &Self) -> Self {
+        let half = T::from(0.5).unwrap();
+        let nearest = |d: T, size: T| -> T { d - size * (d / size - half).ceil() };
+
+        let displacement = other.clone() - self.clone();
+        Vector3d {
+            i: nearest(displacement.i, box_size.i),
+            j: nearest(displacement.j, box_size.j),
+            k: nearest(displacement.k, box_size.k),
+        }
+    }
+
+    /// Calculates the mass-weighted average position of a set of point masses.
+    ///
+    /// # Returns
+    /// - `Some(Self)`: `Σ mᵢ·pᵢ / Σ mᵢ`.
+    /// - `None`: If `points` and `masses` have different lengths or the total mass is zero.
+    pub fn center_of_mass(points: &[Self], masses: &[f32]) -> Option<Self> {
+        if points.len() != masses.len() {
+            return None;
+        }
+
+        let total_mass: f32 = masses.iter().sum();
+        if total_mass == 0.0 {
+            return None;
+        }
+
+        let mut weighted_sum = zero();
+        for (point, mass) in points.iter().zip(masses.iter()) {
+            weighted_sum += point.clone() * T::from(*mass).unwrap();
+        }
+
+        Some(weighted_sum * (T::one() / T::from(total_mass).unwrap()))
+    }
+
+    /// Rotates the vector_3d about an axis through the origin using Rodrigues' rotation formula.
+    ///
+    /// # Parameters
+    /// - `axis`: The rotation axis; it is normalized internally and must be non-zero.
+    /// - `angle_rad`: The rotation angle in radians, counter-clockwise when looking down `axis`
+    ///   (right-hand rule).
+    pub fn rotate_around_axis(&self, axis: &Self, angle_rad: f32) -> Self {
+        let axis = axis.unit_vector();
+        let (sin, cos) = angle_rad.sin_cos();
+
+        let parallel = axis.clone() * T::from(self.dot(&axis) * (1.0 - cos)).unwrap();
+        let perpendicular = axis.cross(self) * T::from(sin).unwrap();
+        self.clone() * T::from(cos).unwrap() + perpendicular + parallel
+    }
+
+    /// Rotates the vector_3d as a point about an axis passing through `pivot` instead of the
+    /// origin: it is translated to the pivot, rotated with `rotate_around_axis` and translated
+    /// back.
+    pub fn rotate_around_point(&self, pivot: &Self, axis: &Self, angle_rad: f32) -> Self {
+        let offset = self.clone() - pivot.clone();
+        offset.rotate_around_axis(axis, angle_rad) + pivot.clone()
+    }
+
+    /// Linearly interpolates towards `other` with a separate blend factor per axis.
+    ///
+    /// Each component of `t` is used for the matching component: `0` keeps `self`, `1` reaches
+    /// `other`. Values outside `[0, 1]` extrapolate.
+    pub fn lerp_per_axis(&self, other: &Self, t: &Self) -> Self {
+        Vector3d {
+            i: self.i + (other.i - self.i) * t.i,
+            j: self.j + (other.j - self.j) * t.j,
+            k: self.k + (other.k - self.k) * t.k,
+        }
+    }
+
+    /// Snaps each component to the nearest multiple of the matching `cell` size.
+    ///
+    /// A cell size of zero disables snapping on that axis, leaving the component unchanged.
+    pub fn snap_to_grid(&self, cell: &Self) -> Self {
+        let snap = |x: T, size: T| -> T {
+            if size == T::zero() {
+                x
+            } else {
+                (x / size).round() * size
+            }
+        };
+
+        Vector3d {
+            i: snap(self.i, cell.i),
+            j: snap(self.j, cell.j),
+            k: snap(self.k, cell.k),
+        }
+    }
+
+    /// Builds a vector_3d by picking each component from `self` where `mask` is `true` and
+    /// from `other` where it is `false`. `mask` is ordered i, j, k.
+    pub fn select_mask(&self, other: &Self, mask: [bool; 3]) -> Self {
+        let pick = |use_self: bool, a: T, b: T| if use_self { a } else { b };
+
+        Vector3d {
+            i: pick(mask[0], self.i, other.i),
+            j: pick(mask[1], self.j, other.j),
+            k: pick(mask[2], self.k, other.k),
+        }
+    }
+
+    /// Returns the parameter `t` in `[0, 1]` of the point on segment `ab` closest to `self`,
+    /// where `t = 0` is `a` and `t = 1` is `b`.
+    ///
+    /// Points beyond either end clamp to that endpoint. A degenerate segment (`a == b`)
+    /// returns `0`.
+    pub fn segment_parameter(&self, a: &Self, b: &Self) -> f32 {
+        let ab = b.clone() - a.clone();
+        let length_squared = ab.dot(&ab);
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+
+        ((self.clone() - a.clone()).dot(&ab) / length_squared).clamp(0.0, 1.0)
+    }
+
+    /// Limits the direction of the vector_3d to a cone around `axis`.
+    ///
+    /// If the angle between `self` and `axis` exceeds `max_angle_rad`, `self` is rotated towards
+    /// `axis` until it lies on the cone boundary; otherwise it is returned unchanged. The
+    /// magnitude of `self` is always preserved. When `self` points exactly opposite `axis` it is
+    /// rotated back through an arbitrary perpendicular plane. Both inputs are assumed non-zero.
+    pub fn clamp_within_cone(&self, axis: &Self, max_angle_rad: f32) -> Self {
+        let (rotation_axis, angle) = self.rotation_to(axis);
+        if angle <= max_angle_rad {
+            return self.clone();
+        }
+
+        self.rotate_around_axis(&rotation_axis, angle - max_angle_rad)
+    }
+
+    /// Converts the components to `f64`.
+    pub fn to_f64(&self) -> Vector3d<f64> {
+        Vector3d {
+            i: self.i.to_f64().unwrap(),
+            j: self.j.to_f64().unwrap(),
+            k: self.k.to_f64().unwrap(),
+        }
+    }
+
+    /// Converts the components to `f32`, rounding to the nearest representable value.
+    pub fn to_f32(&self) -> Vector3d<f32> {
+        Vector3d {
+            i: self.i.to_f32().unwrap(),
+            j: self.j.to_f32().unwrap(),
+            k: self.k.to_f32().unwrap(),
+        }
+    }
+
+    /// Expresses the vector_3d in the basis `e1`, `e2`, `e3`, which need not be orthonormal.
+    ///
+    /// The 3×3 system `c1·e1 + c2·e2 + c3·e3 = self` is solved with Cramer's rule, each
+    /// determinant being a scalar triple product.
+    ///
+    /// # Returns
+    /// - `Some(Self)`: The coefficients `(c1, c2, c3)` as the i, j and k components.
+    /// - `None`: If the basis is degenerate (the three vectors are coplanar).
+    pub fn coordinates_in_basis(&self, e1: &Self, e2: &Self, e3: &Self) -> Option<Self> {
+        let determinant = e1.dot(&e2.cross(e3));
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let c1 = self.dot(&e2.cross(e3)) / determinant;
+        let c2 = e1.dot(&self.cross(e3)) / determinant;
+        let c3 = e1.dot(&e2.cross(self)) / determinant;
+        Some(Vector3d {
+            i: T::from(c1).unwrap(),
+            j: T::from(c2).unwrap(),
+            k: T::from(c3).unwrap(),
+        })
+    }
+
+    /// Moves towards `target` by at most `max_distance`.
+    ///
+    /// If the target is within `max_distance` it is returned exactly, so the step never
+    /// overshoots. A negative `max_distance` is treated as zero.
+    pub fn step_towards(&self, target: &Self, max_distance: f32) -> Self {
+        let max_distance = max_distance.max(0.0);
+        let offset = target.clone() - self.clone();
+        let distance = offset.magnitude();
+        if distance <= max_distance {
+            return target.clone();
+        }
+
+        let step = T::from(max_distance / distance).unwrap();
+        self.clone() + offset * step
+    }
+
+    /// Alias for `step_towards`.
+    pub fn move_towards(&self, target: &Self, max_distance: f32) -> Self {
+        self.step_towards(target, max_distance)
+    }
+
+    /// Applies the Euclidean remainder per component, so negative inputs wrap into
+    /// `[0, |divisor|)`; `-1` with a divisor of `4` gives `3`.
+    ///
+    /// Unlike `wrap`, the sign of the divisor is ignored. A zero divisor component yields `NaN`
+    /// for that component.
+    pub fn rem_euclid_components(&self, divisor: &Self) -> Self {
+        let rem_euclid = |x: T, d: T| -> T {
+            let r = x % d;
+            if r >= T::zero() {
+                return r;
+            }
+
+            let wrapped = r + d.abs();
+            // a tiny negative r can round up to exactly |d|
+            if wrapped >= d.abs() {
+                wrapped - d.abs()
+            } else {
+                wrapped
+            }
+        };
+
+        Vector3d {
+            i: rem_euclid(self.i, divisor.i),
+            j: rem_euclid(self.j, divisor.j),
+            k: rem_euclid(self.k, divisor.k),
+        }
+    }
+
+    /// Returns some vector_3d perpendicular to `self`.
+    ///
+    /// `self` is crossed with whichever basis axis it is least aligned with (its smallest
+    /// absolute component), which keeps the result well away from zero for any non-zero input.
+    /// The result is not normalized.
+    pub fn any_perpendicular(&self) -> Self {
+        let least_aligned = if self.i.abs() <= self.j.abs() && self.i.abs() <= self.k.abs() {
+            i_hat()
+        } else if self.j.abs() <= self.k.abs() {
+            j_hat()
+        } else {
+            k_hat()
+        };
+
+        self.cross(&least_aligned)
+    }
+
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             inner: self,
@@ -286,6 +932,13 @@ impl<T: Float + Copy + Clone> Vector3d<T> {
     }
 }
 
+impl<T: Float + Display> Vector3d<T> {
+    /// Writes the vector_3d as a CSV row `i,j,k` followed by a newline.
+    pub fn write_csv_row<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        writeln!(w, "{},{},{}", self.i, self.j, self.k)
+    }
+}
+
 impl<T> Index<u8> for Vector3d<T> {
     type Output = T;
 
@@ -0,0 +1,41 @@
+use crate::vector_3d::Vector3d;
+use num_traits::Float;
+
+/// A `Vector3d` wrapper whose `PartialEq` compares components within a tolerance.
+///
+/// The derived `PartialEq` on `Vector3d` is exact, which rarely holds after arithmetic. Wrapping
+/// both sides lets tolerant comparisons be written directly with `assert_eq!`. When the two
+/// sides carry different tolerances the larger one is used, so the comparison stays symmetric.
+///
+/// # Example
+/// ```
+/// use math_lib::vector_3d::approx::ApproxVector3d;
+/// use math_lib::vector_3d::Vector3d;
+///
+/// let sum = Vector3d::new(0.1f64, 0.2, 0.0) + Vector3d::new(0.2, 0.1, 0.0);
+/// let expected = Vector3d::new(0.3f64, 0.3, 0.0);
+///
+/// assert_ne!(sum, expected);
+/// assert_eq!(
+///     ApproxVector3d::new(sum, 1e-6),
+///     ApproxVector3d::new(expected, 1e-6),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApproxVector3d<T> {
+    pub vector: Vector3d<T>,
+    pub epsilon: f32,
+}
+
+impl<T: Float> ApproxVector3d<T> {
+    pub fn new(vector: Vector3d<T>, epsilon: f32) -> Self {
+        ApproxVector3d { vector, epsilon }
+    }
+}
+
+impl<T: Float> PartialEq for ApproxVector3d<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector
+            .approx_eq(&other.vector, self.epsilon.max(other.epsilon))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::vector_3d::Vector3d;
+use num_traits::Float;
+
+/// Evaluates a uniform Catmull-Rom segment between `p1` and `p2`.
+///
+/// `p0` and `p3` are the neighbouring control points that shape the tangents at either end.
+/// `t = 0` returns `p1` and `t = 1` returns `p2`.
+pub fn catmull_rom<T: Float>(
+    p0: &Vector3d<T>,
+    p1: &Vector3d<T>,
+    p2: &Vector3d<T>,
+    p3: &Vector3d<T>,
+    t: f32,
+) -> Vector3d<T> {
+    let t = T::from(t).unwrap();
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let half = T::from(0.5).unwrap();
+
+    let term = |a: T, b: T, c: T, d: T| -> T {
+        let two = T::from(2.0).unwrap();
+        let three = T::from(3.0).unwrap();
+        let four = T::from(4.0).unwrap();
+        let five = T::from(5.0).unwrap();
+
+        half * (two * b
+            + (c - a) * t
+            + (two * a - five * b + four * c - d) * t2
+            + (three * b - a - three * c + d) * t3)
+    };
+
+    Vector3d {
+        i: term(p0.i, p1.i, p2.i, p3.i),
+        j: term(p0.j, p1.j, p2.j, p3.j),
+        k: term(p0.k, p1.k, p2.k, p3.k),
+    }
+}
+
+/// A Catmull-Rom spline passing through every point of a path.
+///
+/// The curve is parameterized so that `t = n` lands exactly on `points[n]`. The first and last
+/// points are duplicated to provide the missing neighbours of the end segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRomPath<T> {
+    pub points: Vec<Vector3d<T>>,
+}
+
+impl<T: Float> CatmullRomPath<T> {
+    pub fn new(points: Vec<Vector3d<T>>) -> Self {
+        CatmullRomPath { points }
+    }
+
+    /// Samples the path at `t`, which spans `[0, points.len() - 1]` and is clamped to that range.
+    ///
+    /// # Panics
+    /// Panics if the path has no points.
+    pub fn sample(&self, t: f32) -> Vector3d<T> {
+        assert!(!self.points.is_empty(), "cannot sample an empty CatmullRomPath");
+
+        let last = self.points.len() - 1;
+        if last == 0 {
+            return self.points[0].clone();
+        }
+
+        let t = t.clamp(0.0, last as f32);
+        let segment = (t.floor() as usize).min(last - 1);
+        let local_t = t - segment as f32;
+
+        let p0 = &self.points[segment.saturating_sub(1)];
+        let p1 = &self.points[segment];
+        let p2 = &self.points[segment + 1];
+        let p3 = &self.points[(segment + 2).min(last)];
+
+        catmull_rom(p0, p1, p2, p3, local_t)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::vector_3d::{zero, Vector3d};
+use num_traits::Float;
+
+/// Streaming mean and covariance of `Vector3d` samples using Welford's algorithm.
+///
+/// Samples are folded in one at a time, so nothing is stored and the result stays numerically
+/// stable even when the samples sit far from the origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningStats<T> {
+    count: usize,
+    mean: Vector3d<T>,
+    /// Running sum of outer products of deviations from the mean.
+    m2: [[T; 3]; 3],
+}
+
+impl<T: Float> RunningStats<T> {
+    pub fn new() -> Self {
+        RunningStats {
+            count: 0,
+            mean: zero(),
+            m2: [[T::zero(); 3]; 3],
+        }
+    }
+
+    /// Folds one sample into the statistics.
+    pub fn push(&mut self, v: Vector3d<T>) {
+        self.count += 1;
+        let n = T::from(self.count).unwrap();
+
+        let delta = v.clone() - self.mean.clone();
+        self.mean += delta.clone() * (T::one() / n);
+        let delta_after = v - self.mean.clone();
+
+        for (row, cells) in self.m2.iter_mut().enumerate() {
+            for (column, cell) in cells.iter_mut().enumerate() {
+                *cell = *cell + delta[row as u8] * delta_after[column as u8];
+            }
+        }
+    }
+
+    /// Number of samples pushed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean of the samples, or the zero vector_3d if none have been pushed.
+    pub fn mean(&self) -> Vector3d<T> {
+        self.mean.clone()
+    }
+
+    /// Population covariance of the samples as a row-major 3×3 array, where `[a][b]` is the
+    /// covariance of components `a` and `b` (0 = i, 1 = j, 2 = k). All zeros if no samples have
+    /// been pushed.
+    pub fn covariance(&self) -> [[T; 3]; 3] {
+        if self.count == 0 {
+            return self.m2;
+        }
+
+        let n = T::from(self.count).unwrap();
+        self.m2.map(|row| row.map(|cell| cell / n))
+    }
+}
+
+impl<T: Float> Default for RunningStats<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::vector::Vector;
+use crate::vector_3d::Vector3d;
+use num_traits::Float;
+
+/// Dimension and component access shared by the crate's vector types, so generic code can be
+/// written once for `Vector3d` and `Vector<T, N>`.
+pub trait VectorLike<T> {
+    /// Number of components.
+    fn dim(&self) -> usize;
+
+    /// The component at index `i`, or `None` if `i >= dim()`.
+    fn get(&self, i: usize) -> Option<T>;
+}
+
+impl<T: Float> VectorLike<T> for Vector3d<T> {
+    fn dim(&self) -> usize {
+        3
+    }
+
+    fn get(&self, i: usize) -> Option<T> {
+        match i {
+            0 => Some(self.i),
+            1 => Some(self.j),
+            2 => Some(self.k),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Float, const N: usize> VectorLike<T> for Vector<T, N> {
+    fn dim(&self) -> usize {
+        N
+    }
+
+    fn get(&self, i: usize) -> Option<T> {
+        self.components.get(i).copied()
+    }
+}
@@ -1,6 +1,13 @@
 #[cfg(test)]
 mod tests {
+    use math_lib::error::MathError;
+    use math_lib::vector::Vector;
+    use math_lib::vector_3d;
+    use math_lib::vector_3d::approx::ApproxVector3d;
+    use math_lib::vector_3d::spline::CatmullRomPath;
+    use math_lib::vector_3d::stats::RunningStats;
     use math_lib::vector_3d::Vector3d;
+    use math_lib::vector_like::VectorLike;
 
     #[test]
     fn test_iter() {
@@ -31,4 +38,685 @@ mod tests {
             *x = *x + 1.0;
         }
     }
+
+    #[test]
+    fn test_cos_angle() {
+        let i = Vector3d::new(1f32, 0.0, 0.0);
+        let j = Vector3d::new(0f32, 2.0, 0.0);
+        let parallel = Vector3d::new(3f32, 0.0, 0.0);
+        let anti_parallel = Vector3d::new(-0.5f32, 0.0, 0.0);
+
+        assert_eq!(i.cos_angle(&j), Some(0.0));
+        assert_eq!(i.cos_angle(&parallel), Some(1.0));
+        assert_eq!(i.cos_angle(&anti_parallel), Some(-1.0));
+        assert_eq!(i.cos_angle(&Vector3d::new(0.0, 0.0, 0.0)), None);
+        assert_eq!(i.cos_angle(&Vector3d::new(f32::INFINITY, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_cos_angle_extreme_magnitudes() {
+        let huge = Vector3d::new(1e20f32, 0.0, 0.0);
+        assert_eq!(huge.cos_angle(&huge), Some(1.0));
+        assert_eq!(huge.cos_angle(&Vector3d::new(0.0, 1e20, 0.0)), Some(0.0));
+
+        let tiny = Vector3d::new(1e-30f32, 0.0, 0.0);
+        assert_eq!(tiny.cos_angle(&tiny), Some(1.0));
+        assert_eq!(tiny.cos_angle(&Vector3d::new(-1e-30, 0.0, 0.0)), Some(-1.0));
+    }
+
+    #[test]
+    fn test_is_parallel_and_perpendicular() {
+        let v = Vector3d::new(1f32, 2.0, 3.0);
+        let parallel = Vector3d::new(2f32, 4.0, 6.0);
+        let anti_parallel = Vector3d::new(-1f32, -2.0, -3.0);
+        let orthogonal = Vector3d::new(3f32, 0.0, -1.0);
+
+        assert!(v.is_parallel(&parallel, 1e-6));
+        assert!(v.is_parallel(&anti_parallel, 1e-6));
+        assert!(!v.is_parallel(&orthogonal, 1e-6));
+
+        assert!(v.is_perpendicular(&orthogonal, 1e-6));
+        assert!(!v.is_perpendicular(&parallel, 1e-6));
+        assert!(!v.is_perpendicular(&anti_parallel, 1e-6));
+    }
+
+    #[test]
+    fn test_normalize_inplace() {
+        let mut vec = Vector::new([3f32, 0.0, 4.0, 0.0]);
+        assert_eq!(vec.normalize_inplace(), Ok(()));
+        assert_eq!(vec, Vector::new([0.6, 0.0, 0.8, 0.0]));
+
+        let mut zero: Vector<f32, 4> = Vector::zero();
+        assert_eq!(zero.normalize_inplace(), Err(MathError::ZeroMagnitude));
+        assert_eq!(zero, Vector::zero());
+    }
+
+    #[test]
+    fn test_split_tangential_normal() {
+        let velocity = Vector3d::new(2f32, 0.0, 0.0);
+        let accel = Vector3d::new(3f32, 4.0, -1.0);
+
+        let (tangential, normal) = vector_3d::split_tangential_normal(&velocity, &accel);
+        assert_eq!(tangential, Vector3d::new(3.0, 0.0, 0.0));
+        assert_eq!(normal, Vector3d::new(0.0, 4.0, -1.0));
+
+        let (tangential, normal) = vector_3d::split_tangential_normal(&vector_3d::zero(), &accel);
+        assert_eq!(tangential, vector_3d::zero());
+        assert_eq!(normal, accel);
+    }
+
+    #[test]
+    fn test_slide_along_surface() {
+        let normal = Vector3d::new(0f32, 0.6, 0.8);
+        let velocity = Vector3d::new(1f32, -2.0, -3.0);
+
+        let slide = velocity.slide_along_surface(&normal);
+        assert!(slide.dot(&normal).abs() < 1e-6);
+        assert_eq!(slide.i, velocity.i);
+    }
+
+    #[test]
+    fn test_all_any() {
+        let positive = Vector3d::new(1f32, 2.0, 3.0);
+        let mixed = Vector3d::new(1f32, -2.0, 3.0);
+
+        assert!(positive.all(|x| x > 0.0));
+        assert!(!mixed.all(|x| x > 0.0));
+        assert!(mixed.any(|x| x < 0.0));
+        assert!(!positive.any(|x| x < 0.0));
+        assert!(Vector3d::new(1f32, f32::NAN, 0.0).any(f32::is_nan));
+    }
+
+    #[test]
+    fn test_clamp_to_unit_cube() {
+        let vec = Vector3d::new(2.5f32, -0.25, -7.0);
+        assert_eq!(vec.clamp_to_unit_cube(), Vector3d::new(1.0, -0.25, -1.0));
+
+        let clamped = Vector3d::new(f32::NAN, 3.0, -0.5).clamp_to_unit_cube();
+        assert!(clamped.i.is_nan());
+        assert_eq!((clamped.j, clamped.k), (1.0, -0.5));
+    }
+
+    #[test]
+    fn test_hadamard_div() {
+        let scaled = Vector::new([2f32, 9.0, -4.0, 0.0]);
+        let scale = Vector::new([2f32, 3.0, 0.0, 0.0]);
+
+        let quotient = scaled.hadamard_div(&scale);
+        assert_eq!(quotient.components[0], 1.0);
+        assert_eq!(quotient.components[1], 3.0);
+        assert_eq!(quotient.components[2], f32::NEG_INFINITY);
+        assert!(quotient.components[3].is_nan());
+    }
+
+    #[test]
+    fn test_project_to_sphere() {
+        let center = Vector3d::new(1f32, 1.0, 1.0);
+        let inside = Vector3d::new(1f32, 1.5, 1.0);
+        let outside = Vector3d::new(1f32, 1.0, 9.0);
+
+        let projected_inside = inside.project_to_sphere(&center, 2.0);
+        let projected_outside = outside.project_to_sphere(&center, 2.0);
+        assert_eq!(projected_inside, Vector3d::new(1.0, 3.0, 1.0));
+        assert_eq!(projected_outside, Vector3d::new(1.0, 1.0, 3.0));
+        assert_eq!((projected_inside - center.clone()).magnitude(), 2.0);
+        assert_eq!((projected_outside - center.clone()).magnitude(), 2.0);
+
+        assert_eq!(center.project_to_sphere(&center, 2.0), Vector3d::new(3.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_catmull_rom_path() {
+        let points = vec![
+            Vector3d::new(0f32, 0.0, 0.0),
+            Vector3d::new(1f32, 2.0, 0.0),
+            Vector3d::new(3f32, 2.0, 1.0),
+            Vector3d::new(4f32, 0.0, 1.0),
+        ];
+        let path = CatmullRomPath::new(points.clone());
+
+        for (index, point) in points.iter().enumerate() {
+            assert_eq!(&path.sample(index as f32), point);
+        }
+        assert_eq!(path.sample(-1.0), points[0]);
+        assert_eq!(path.sample(10.0), points[3]);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vector3d::new(1f32, 2.0, 3.0);
+        let b = Vector3d::new(4f32, -2.0, 3.0);
+
+        assert_eq!((b.clone() - a.clone()).magnitude(), 5.0);
+        assert_eq!(a.manhattan_distance(&b), 7.0);
+        assert_eq!(a.chebyshev_distance(&b), 4.0);
+        assert_eq!(b.manhattan_distance(&a), 7.0);
+        assert_eq!(b.chebyshev_distance(&a), 4.0);
+    }
+
+    #[test]
+    fn test_approx_vector_3d() {
+        let sum = Vector3d::new(0.1f64, 0.2, 0.0) + Vector3d::new(0.2f64, 0.1, 0.0);
+        let expected = Vector3d::new(0.3f64, 0.3, 0.0);
+
+        assert_ne!(sum, expected);
+        assert!(sum.approx_eq(&expected, 1e-6));
+        assert_eq!(
+            ApproxVector3d::new(sum.clone(), 1e-6),
+            ApproxVector3d::new(expected.clone(), 1e-6)
+        );
+        assert_ne!(
+            ApproxVector3d::new(sum, 1e-6),
+            ApproxVector3d::new(Vector3d::new(0.3f64, 0.31, 0.0), 1e-6)
+        );
+    }
+
+    #[test]
+    fn test_to_color_bytes() {
+        assert_eq!(Vector3d::new(1f32, 0.5, 0.0).to_color_bytes(), [255, 128, 0]);
+        assert_eq!(Vector3d::new(2f32, -1.0, f32::NAN).to_color_bytes(), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_from_color_bytes() {
+        let color: Vector3d<f32> = Vector3d::from_color_bytes([255, 128, 0]);
+        assert_eq!(color.i, 1.0);
+        assert!((color.j - 0.5).abs() < 1.0 / 255.0);
+        assert_eq!(color.k, 0.0);
+        assert_eq!(color.to_color_bytes(), [255, 128, 0]);
+
+        let original = Vector3d::new(0.2f32, 0.4, 0.9);
+        let round_trip: Vector3d<f32> = Vector3d::from_color_bytes(original.to_color_bytes());
+        assert!(round_trip.approx_eq(&original, 1.0 / 255.0));
+
+        let precise: Vector3d<f64> = Vector3d::from_color_bytes([255, 128, 0]);
+        assert_eq!(precise.j, 128.0 / 255.0);
+    }
+
+    #[test]
+    fn test_dot_weighted() {
+        let a = Vector::new([1f32, 2.0, 3.0, 4.0]);
+        let b = Vector::new([-2f32, 0.5, 1.0, 2.0]);
+
+        assert_eq!(a.dot_weighted(&b, &Vector::new([1.0; 4])), a.dot(&b));
+        assert_eq!(a.dot_weighted(&b, &Vector::new([0.0, 2.0, 1.0, 0.5])), 9.0);
+    }
+
+    #[test]
+    fn test_barycentric_coords() {
+        let a = Vector3d::new(0f32, 0.0, 1.0);
+        let b = Vector3d::new(2f32, 0.0, 1.0);
+        let c = Vector3d::new(0f32, 2.0, 1.0);
+
+        assert_eq!(Vector3d::barycentric_coords(&a, &a, &b, &c), Some((1.0, 0.0, 0.0)));
+        assert_eq!(Vector3d::barycentric_coords(&b, &a, &b, &c), Some((0.0, 1.0, 0.0)));
+        assert_eq!(Vector3d::barycentric_coords(&c, &a, &b, &c), Some((0.0, 0.0, 1.0)));
+
+        let midpoint = Vector3d::new(1f32, 1.0, 1.0);
+        assert_eq!(Vector3d::barycentric_coords(&midpoint, &a, &b, &c), Some((0.0, 0.5, 0.5)));
+
+        let collinear = Vector3d::new(4f32, 0.0, 1.0);
+        assert_eq!(Vector3d::barycentric_coords(&a, &a, &b, &collinear), None);
+    }
+
+    #[test]
+    fn test_reflect() {
+        let normal = Vector3d::new(0f32, 1.0, 0.0);
+        let incident = Vector3d::new(1f32, -1.0, 0.0);
+        assert_eq!(incident.reflect(&normal), Vector3d::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_refract() {
+        let normal = Vector3d::new(0f32, 0.0, 1.0);
+
+        let head_on = Vector3d::new(0f32, 0.0, -1.0);
+        assert_eq!(head_on.refract(&normal, 1.5), Some(head_on.clone()));
+
+        let same_medium = Vector3d::new(0.6f32, 0.0, -0.8);
+        let transmitted = same_medium.refract(&normal, 1.0).unwrap();
+        assert!(transmitted.approx_eq(&same_medium, 1e-6));
+
+        let grazing = Vector3d::new(0.8f32, 0.0, -0.6);
+        assert_eq!(grazing.refract(&normal, 1.5), None);
+    }
+
+    #[test]
+    fn test_total_cmp_components() {
+        let mut vectors = vec![
+            Vector3d::new(1f32, 0.0, 0.0),
+            Vector3d::new(0f32, 2.0, 1.0),
+            Vector3d::new(0f32, 2.0, -1.0),
+            Vector3d::new(-3f32, 5.0, 5.0),
+        ];
+        vectors.sort_by(|a, b| a.total_cmp_components(b));
+
+        assert_eq!(
+            vectors,
+            vec![
+                Vector3d::new(-3.0, 5.0, 5.0),
+                Vector3d::new(0.0, 2.0, -1.0),
+                Vector3d::new(0.0, 2.0, 1.0),
+                Vector3d::new(1.0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mean_variance() {
+        let vec = Vector::new([2f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(vec.mean(), 5.0);
+        assert_eq!(vec.variance(), 4.0);
+    }
+
+    #[test]
+    fn test_standardize() {
+        let vec = Vector::new([1f32, 3.0, 4.0, 10.0, -2.0]);
+        let standardized = vec.standardize().unwrap();
+        assert!(standardized.mean().abs() < 1e-6);
+        assert!((standardized.variance() - 1.0).abs() < 1e-6);
+
+        assert_eq!(Vector::new([2f32; 4]).standardize(), None);
+    }
+
+    #[test]
+    fn test_running_stats() {
+        let samples = [
+            Vector3d::new(1000.0f64, 2.0, -3.0),
+            Vector3d::new(1002.0f64, 1.0, -1.0),
+            Vector3d::new(999.0f64, 4.0, -2.0),
+            Vector3d::new(1003.0f64, 5.0, -6.0),
+            Vector3d::new(1001.0f64, 3.0, -3.0),
+        ];
+
+        let mut stats = RunningStats::new();
+        for sample in samples.iter() {
+            stats.push(sample.clone());
+        }
+
+        let n = samples.len() as f64;
+        let mut batch_mean = vector_3d::zero();
+        for sample in samples.iter() {
+            batch_mean += sample.clone() * (1.0 / n);
+        }
+
+        let mut batch_covariance = [[0.0; 3]; 3];
+        for sample in samples.iter() {
+            let deviation = sample.clone() - batch_mean.clone();
+            for (row, cells) in batch_covariance.iter_mut().enumerate() {
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    *cell += deviation[row as u8] * deviation[column as u8] / n;
+                }
+            }
+        }
+
+        assert_eq!(stats.count(), samples.len());
+        assert!(stats.mean().approx_eq(&batch_mean, 1e-9));
+        for (streamed, batch) in stats.covariance().iter().zip(batch_covariance.iter()) {
+            for (streamed, batch) in streamed.iter().zip(batch.iter()) {
+                assert!((streamed - batch).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let values = [1f32, 2.0, 3.0, 4.0];
+        assert_eq!(Vector3d::try_from_slice(&values[..2]), None);
+        assert_eq!(
+            Vector3d::try_from_slice(&values[..3]),
+            Some(Vector3d::new(1.0, 2.0, 3.0))
+        );
+        assert_eq!(Vector3d::try_from_slice(&values), None);
+    }
+
+    #[test]
+    fn test_pad_pow2() {
+        let padded: Vector<f32, 4> = Vector::new([1f32, 2.0, 3.0]).pad_pow2(0.0);
+        assert_eq!(padded, Vector::new([1.0, 2.0, 3.0, 0.0]));
+
+        let unchanged = Vector::new([1f32, 2.0]).pad_pow2::<2>(9.0);
+        assert_eq!(unchanged, Vector::new([1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_rotation_to() {
+        let i = Vector3d::new(2f32, 0.0, 0.0);
+        let j = Vector3d::new(0f32, 3.0, 0.0);
+
+        let (axis, angle) = i.rotation_to(&j);
+        assert_eq!(axis, Vector3d::new(0.0, 0.0, 1.0));
+        assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+
+        let (axis, angle) = i.rotation_to(&Vector3d::new(5.0, 0.0, 0.0));
+        assert_eq!(angle, 0.0);
+        assert!((axis.magnitude() - 1.0).abs() < 1e-6);
+
+        let (axis, angle) = i.rotation_to(&Vector3d::new(-1.0, 0.0, 0.0));
+        assert_eq!(angle, std::f32::consts::PI);
+        assert!((axis.magnitude() - 1.0).abs() < 1e-6);
+        assert!(axis.is_perpendicular(&i, 1e-6));
+    }
+
+    #[test]
+    fn test_project_clamped() {
+        let axis = Vector3d::new(0f32, 2.0, 0.0);
+        let u = Vector3d::new(1f32, 10.0, -3.0);
+
+        assert_eq!(Vector3d::project(&u, &axis), Vector3d::new(0.0, 10.0, 0.0));
+        assert_eq!(
+            Vector3d::project_clamped(&u, &axis, -1.0, 2.0),
+            Vector3d::new(0.0, 4.0, 0.0)
+        );
+        assert_eq!(
+            Vector3d::project_clamped(&(u * -1.0), &axis, -1.0, 2.0),
+            Vector3d::new(0.0, -2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_reflect_two_sided() {
+        let normal = Vector3d::new(0f32, 1.0, 0.0);
+
+        let from_front = Vector3d::new(1f32, -2.0, 0.0);
+        assert_eq!(from_front.reflect_two_sided(&normal), Vector3d::new(1.0, 2.0, 0.0));
+
+        let from_back = Vector3d::new(1f32, 2.0, 0.0);
+        assert_eq!(from_back.reflect_two_sided(&normal), Vector3d::new(1.0, -2.0, 0.0));
+        assert_eq!(
+            from_back.reflect_two_sided(&(normal.clone() * -1.0)),
+            Vector3d::new(1.0, -2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_equals_approx() {
+        let a = Vector::new([0.1f64, 0.2, 0.3, 0.4, 0.5, 0.6]);
+        let b = Vector::new([0.1f64 + 1e-9, 0.2, 0.3 - 1e-9, 0.4, 0.5, 0.6 + 1e-9]);
+        let c = Vector::new([0.1f64, 0.2, 0.3, 0.4, 0.5, 1.6]);
+
+        assert_ne!(a, b);
+        assert!(a.equals_approx(&b, 1e-6));
+        assert!(!a.equals_approx(&c, 1e-6));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let box_size = Vector3d::new(10f32, 10.0, 4.0);
+        let position = Vector3d::new(23.5f32, -1.0, 2.0);
+
+        assert_eq!(position.wrap(&box_size), Vector3d::new(3.5, 9.0, 2.0));
+        assert_eq!(Vector3d::new(-1e-10f32, 10.0, -8.0).wrap(&box_size), vector_3d::zero());
+    }
+
+    #[test]
+    fn test_minimum_image() {
+        let box_size = Vector3d::new(10f32, 10.0, 10.0);
+        let a = Vector3d::new(1f32, 5.0, 9.0);
+        let b = Vector3d::new(9f32, 6.0, 4.0);
+
+        assert_eq!(b.clone() - a.clone(), Vector3d::new(8.0, 1.0, -5.0));
+        assert_eq!(a.minimum_image(&b, &box_size), Vector3d::new(-2.0, 1.0, 5.0));
+        assert_eq!(b.minimum_image(&a, &box_size), Vector3d::new(2.0, -1.0, 5.0));
+    }
+
+    #[test]
+    fn test_write_csv_row() {
+        let mut buffer = Vec::new();
+        Vector3d::new(1f32, -2.5, 0.0).write_csv_row(&mut buffer).unwrap();
+        Vector3d::new(3f32, 4.0, 5.25).write_csv_row(&mut buffer).unwrap();
+
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1,-2.5,0\n3,4,5.25\n");
+    }
+
+    #[test]
+    fn test_center_of_mass() {
+        let points = [Vector3d::new(0f32, 0.0, 0.0), Vector3d::new(4f32, 2.0, -2.0)];
+
+        assert_eq!(
+            Vector3d::center_of_mass(&points, &[1.0, 1.0]),
+            Some(Vector3d::new(2.0, 1.0, -1.0))
+        );
+        assert_eq!(
+            Vector3d::center_of_mass(&points, &[1.0, 3.0]),
+            Some(Vector3d::new(3.0, 1.5, -1.5))
+        );
+        assert_eq!(Vector3d::center_of_mass(&points, &[1.0]), None);
+        assert_eq!(Vector3d::center_of_mass(&points, &[0.0, 0.0]), None);
+
+        let precise = [Vector3d::new(0f64, 0.0, 0.0), Vector3d::new(1f64, 0.0, 0.0)];
+        let center = Vector3d::center_of_mass(&precise, &[2.0, 1.0]).unwrap();
+        assert_eq!(center.i, 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_inertia_tensor() {
+        let center = Vector3d::new(1f32, 1.0, 1.0);
+        let points = [
+            Vector3d::new(2f32, 1.0, 1.0),
+            Vector3d::new(0f32, 1.0, 1.0),
+            Vector3d::new(1f32, 3.0, 1.0),
+            Vector3d::new(1f32, -1.0, 1.0),
+        ];
+
+        let tensor = vector_3d::inertia_tensor(&points, &[2.0, 2.0, 1.0, 1.0], center);
+        assert_eq!(tensor, [[8.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 12.0]]);
+    }
+
+    #[test]
+    fn test_rotate_around_axis() {
+        let rotated = Vector3d::new(1f32, 0.0, 0.0)
+            .rotate_around_axis(&Vector3d::new(0.0, 0.0, 2.0), std::f32::consts::FRAC_PI_2);
+        assert!(rotated.approx_eq(&Vector3d::new(0.0, 1.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn test_rotate_around_point() {
+        let point = Vector3d::new(3f32, 1.0, 5.0);
+        let pivot = Vector3d::new(2f32, 1.0, 0.0);
+        let axis = Vector3d::new(0f32, 0.0, 1.0);
+
+        let rotated = point.rotate_around_point(&pivot, &axis, std::f32::consts::PI);
+        assert!(rotated.approx_eq(&Vector3d::new(1.0, 1.0, 5.0), 1e-6));
+    }
+
+    #[test]
+    fn test_lerp_per_axis() {
+        let from = Vector3d::new(0f32, 10.0, -4.0);
+        let to = Vector3d::new(8f32, 20.0, 4.0);
+        let t = Vector3d::new(0.25f32, 1.0, 0.0);
+
+        assert_eq!(from.lerp_per_axis(&to, &t), Vector3d::new(2.0, 20.0, -4.0));
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let point = Vector3d::new(1.2f32, -0.8, 3.3);
+
+        assert_eq!(
+            point.snap_to_grid(&Vector3d::new(0.5, 0.5, 0.5)),
+            Vector3d::new(1.0, -1.0, 3.5)
+        );
+        assert_eq!(
+            point.snap_to_grid(&Vector3d::new(0.5, 0.0, 2.0)),
+            Vector3d::new(1.0, -0.8, 4.0)
+        );
+    }
+
+    #[test]
+    fn test_select_mask() {
+        let a = Vector3d::new(1f32, 2.0, 3.0);
+        let b = Vector3d::new(-1f32, -2.0, -3.0);
+
+        assert_eq!(a.select_mask(&b, [true, false, true]), Vector3d::new(1.0, -2.0, 3.0));
+        assert_eq!(a.select_mask(&b, [false; 3]), b);
+    }
+
+    #[test]
+    fn test_top_k_indices() {
+        let vec = Vector::new([0.5f32, -9.0, 2.0, 7.0, -2.0]);
+
+        assert_eq!(vec.top_k_indices(2), vec![1, 3]);
+        assert_eq!(vec.top_k_indices(4), vec![1, 3, 2, 4]);
+        assert_eq!(vec.top_k_indices(10), vec![1, 3, 2, 4, 0]);
+        assert!(vec.top_k_indices(0).is_empty());
+    }
+
+    #[test]
+    fn test_keep_top_k() {
+        let vec = Vector::new([0.5f32, -9.0, 2.0, 7.0, -1.0]);
+
+        assert_eq!(vec.keep_top_k(2), Vector::new([0.0, -9.0, 0.0, 7.0, 0.0]));
+        assert_eq!(vec.keep_top_k(5), vec);
+        assert_eq!(vec.keep_top_k(0), Vector::zero());
+    }
+
+    #[test]
+    fn test_segment_parameter() {
+        let a = Vector3d::new(0f32, 0.0, 0.0);
+        let b = Vector3d::new(4f32, 0.0, 0.0);
+
+        assert_eq!(Vector3d::new(1f32, 3.0, 0.0).segment_parameter(&a, &b), 0.25);
+        assert_eq!(Vector3d::new(-2f32, 1.0, 0.0).segment_parameter(&a, &b), 0.0);
+        assert_eq!(Vector3d::new(7f32, 0.0, -1.0).segment_parameter(&a, &b), 1.0);
+        assert_eq!(Vector3d::new(7f32, 0.0, -1.0).segment_parameter(&a, &a), 0.0);
+    }
+
+    #[test]
+    fn test_clamp_within_cone() {
+        let axis = Vector3d::new(0f32, 0.0, 1.0);
+        let max_angle = std::f32::consts::FRAC_PI_4;
+
+        let inside = Vector3d::new(0.1f32, 0.0, 1.0);
+        assert_eq!(inside.clamp_within_cone(&axis, max_angle), inside);
+
+        let outside = Vector3d::new(2f32, 0.0, 0.0);
+        let clamped = outside.clamp_within_cone(&axis, max_angle);
+        let expected = Vector3d::new(2f32.sqrt(), 0.0, 2f32.sqrt());
+        assert!(clamped.approx_eq(&expected, 1e-6));
+        assert!((clamped.angle_rad(&axis) - max_angle).abs() < 1e-6);
+        assert!((clamped.magnitude() - 2.0).abs() < 1e-6);
+
+        let opposite = Vector3d::new(0f32, 0.0, -3.0);
+        let clamped = opposite.clamp_within_cone(&axis, max_angle);
+        assert!((clamped.angle_rad(&axis) - max_angle).abs() < 1e-5);
+        assert!((clamped.magnitude() - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_iter_indexed() {
+        let vec = Vector::new([4f32, 5.0, 6.0]);
+        let pairs: Vec<(usize, &f32)> = vec.iter_indexed().collect();
+
+        assert_eq!(pairs, vec![(0, &4.0), (1, &5.0), (2, &6.0)]);
+    }
+
+    #[test]
+    fn test_vector_like() {
+        fn components<V: VectorLike<f32>>(vector: &V) -> Vec<f32> {
+            (0..vector.dim()).map(|i| vector.get(i).unwrap()).collect()
+        }
+
+        let vec_3d = Vector3d::new(1f32, 2.0, 3.0);
+        let vec_5 = Vector::new([1f32, 2.0, 3.0, 4.0, 5.0]);
+
+        assert_eq!(components(&vec_3d), vec![1.0, 2.0, 3.0]);
+        assert_eq!(components(&vec_5), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(vec_3d.get(3), None);
+        assert_eq!(vec_5.get(5), None);
+    }
+
+    #[test]
+    fn test_precision_conversion() {
+        let single = Vector3d::new(1.5f32, -0.1, 1e10);
+        let double = single.to_f64();
+        assert_eq!(double, Vector3d::new(1.5, -0.1f32 as f64, 1e10));
+        assert_eq!(double.to_f32(), single);
+
+        let precise = Vector3d::new(0.1f64, 1.0 / 3.0, -2.0);
+        assert!(precise.to_f32().to_f64().approx_eq(&precise, 1e-7));
+    }
+
+    #[test]
+    fn test_coordinates_in_basis() {
+        let v = Vector3d::new(2f32, -3.0, 5.0);
+        let (i, j, k) = (vector_3d::i_hat(), vector_3d::j_hat(), vector_3d::k_hat());
+        assert_eq!(v.coordinates_in_basis(&i, &j, &k), Some(v.clone()));
+
+        let e1 = Vector3d::new(1f32, 1.0, 0.0);
+        let e2 = Vector3d::new(0f32, 2.0, 0.0);
+        let e3 = Vector3d::new(1f32, 0.0, 1.0);
+        let coordinates = v.coordinates_in_basis(&e1, &e2, &e3).unwrap();
+        let rebuilt = e1 * coordinates.i + e2 * coordinates.j + e3.clone() * coordinates.k;
+        assert!(rebuilt.approx_eq(&v, 1e-6));
+
+        let coplanar = Vector3d::new(1f32, 3.0, 0.0);
+        assert_eq!(v.coordinates_in_basis(&i, &j, &coplanar), None);
+    }
+
+    #[test]
+    fn test_with_appended() {
+        let homogeneous: Vector<f32, 4> = Vector::new([1f32, 2.0, 3.0]).with_appended(1.0);
+        assert_eq!(homogeneous, Vector::new([1.0, 2.0, 3.0, 1.0]));
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let start = Vector3d::new(0f32, 0.0, 0.0);
+        let target = Vector3d::new(3f32, 4.0, 0.0);
+
+        assert_eq!(start.step_towards(&target, 2.5), Vector3d::new(1.5, 2.0, 0.0));
+        assert_eq!(start.step_towards(&target, 7.0), target);
+        assert_eq!(start.move_towards(&target, 7.0), target);
+        assert_eq!(start.step_towards(&target, -1.0), start);
+        assert_eq!(start.step_towards(&start, -1.0), start);
+    }
+
+    #[test]
+    fn test_rem_euclid_components() {
+        let vec = Vector3d::new(-1f32, 9.5, 7.0);
+        let divisor = Vector3d::new(4f32, 4.0, -3.0);
+        assert_eq!(vec.rem_euclid_components(&divisor), Vector3d::new(3.0, 1.5, 1.0));
+
+        let tiny_negative = Vector3d::new(-1e-10f32, 0.0, -8.0);
+        let wrapped = tiny_negative.rem_euclid_components(&Vector3d::new(4.0, 4.0, -4.0));
+        assert_eq!(wrapped, vector_3d::zero());
+
+        let zero_divisor = Vector3d::new(4f32, 0.0, 4.0);
+        assert!(vec.rem_euclid_components(&zero_divisor).j.is_nan());
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let ramp: Vector<f32, 4> = Vector::from_fn(|i| i as f32);
+        assert_eq!(ramp, Vector::new([0.0, 1.0, 2.0, 3.0]));
+
+        let basis: Vector<f32, 3> = Vector::from_fn(|i| if i == 1 { 1.0 } else { 0.0 });
+        assert_eq!(basis, Vector::new([0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_any_perpendicular() {
+        let inputs = [
+            Vector3d::new(1f32, 0.0, 0.0),
+            Vector3d::new(0f32, -2.0, 0.0),
+            Vector3d::new(0f32, 0.0, 3.0),
+            Vector3d::new(1f32, 1.0, 1.0),
+            Vector3d::new(0.001f32, 5.0, -0.002),
+            Vector3d::new(-4f32, 2.0, 7.5),
+        ];
+
+        for input in inputs.iter() {
+            let perpendicular = input.any_perpendicular();
+            assert!(perpendicular.magnitude() > 0.0);
+            assert!(input.dot(&perpendicular).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample an empty CatmullRomPath")]
+    fn test_catmull_rom_path_empty() {
+        let path: CatmullRomPath<f32> = CatmullRomPath::new(Vec::new());
+        path.sample(0.0);
+    }
 }